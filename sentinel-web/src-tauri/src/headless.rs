@@ -0,0 +1,207 @@
+//! Opt-in headless mode: runs the backend supervisor without the Tauri
+//! WebView and renders a ratatui terminal dashboard instead, for server/CI
+//! hosts that have no display. Drives the same `run_supervisor` actor as the
+//! GUI path, just wired to a broadcast channel instead of Tauri events.
+
+use std::io::Stdout;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crossterm::event::{self, Event, KeyCode};
+use crossterm::execute;
+use crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::backend::{Backend, CrosstermBackend};
+use ratatui::layout::{Constraint, Direction, Layout};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::{Frame, Terminal};
+use tokio::sync::{broadcast, mpsc};
+
+use crate::{
+    kill_backend, run_supervisor, AtomicBackendStatus, BackendProcess, BackendStatus, Notifier,
+    SharedStatus, SupervisorCommand, SupervisorEvent,
+};
+
+/// Maximum number of log lines kept for the scrolling tail.
+const LOG_HISTORY_LIMIT: usize = 500;
+/// How often the dashboard redraws and checks for a keypress.
+const TICK_INTERVAL: Duration = Duration::from_millis(150);
+
+/// Whether `--headless` was passed on the command line.
+pub fn is_requested() -> bool {
+    std::env::args().any(|arg| arg == "--headless")
+}
+
+/// Entry point for headless mode, called from `main` before `tauri::Builder`
+/// is ever touched.
+pub fn run() {
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("failed to start headless runtime");
+
+    runtime.block_on(run_async());
+}
+
+async fn run_async() {
+    let backend = Arc::new(Mutex::new(BackendProcess { child: None, port: None }));
+    let status: SharedStatus = Arc::new(AtomicBackendStatus::new(BackendStatus::Starting));
+    let (events_tx, events_rx) = broadcast::channel(256);
+    let (commands_tx, commands_rx) = mpsc::unbounded_channel();
+
+    let supervisor = tokio::spawn(run_supervisor(
+        Notifier::Channel(events_tx),
+        backend.clone(),
+        status.clone(),
+        commands_rx,
+    ));
+
+    if let Err(e) = run_dashboard(status.clone(), events_rx, commands_tx.clone()).await {
+        eprintln!("❌ Dashboard error: {}", e);
+    }
+
+    // The dashboard returns when the user quits or its terminal I/O fails;
+    // either way, ask the supervisor to clean up the child process and wait
+    // for it to actually do so.
+    let _ = commands_tx.send(SupervisorCommand::Shutdown);
+    let _ = supervisor.await;
+}
+
+/// State the dashboard renders; updated as `SupervisorEvent`s arrive.
+struct DashboardState {
+    status: BackendStatus,
+    port: Option<u16>,
+    log_lines: Vec<String>,
+}
+
+fn status_label(status: BackendStatus) -> &'static str {
+    match status {
+        BackendStatus::Starting => "starting",
+        BackendStatus::Ready => "ready",
+        BackendStatus::Crashed => "crashed",
+        BackendStatus::Restarting => "restarting",
+    }
+}
+
+fn status_color(status: BackendStatus) -> Color {
+    match status {
+        BackendStatus::Ready => Color::Green,
+        BackendStatus::Starting | BackendStatus::Restarting => Color::Yellow,
+        BackendStatus::Crashed => Color::Red,
+    }
+}
+
+async fn run_dashboard(
+    status: SharedStatus,
+    mut events: broadcast::Receiver<SupervisorEvent>,
+    commands: mpsc::UnboundedSender<SupervisorCommand>,
+) -> std::io::Result<()> {
+    enable_raw_mode()?;
+    let mut stdout: Stdout = std::io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+    let mut state = DashboardState {
+        status: status.load(),
+        port: None,
+        log_lines: Vec::new(),
+    };
+
+    let result = dashboard_loop(&mut terminal, &mut state, &status, &mut events, &commands).await;
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+    terminal.show_cursor()?;
+
+    result
+}
+
+async fn dashboard_loop<B: Backend>(
+    terminal: &mut Terminal<B>,
+    state: &mut DashboardState,
+    status: &SharedStatus,
+    events: &mut broadcast::Receiver<SupervisorEvent>,
+    commands: &mpsc::UnboundedSender<SupervisorCommand>,
+) -> std::io::Result<()> {
+    let mut tick = tokio::time::interval(TICK_INTERVAL);
+    loop {
+        terminal.draw(|frame| draw(frame, state))?;
+
+        tokio::select! {
+            event = events.recv() => {
+                match event {
+                    Ok(SupervisorEvent::Status(new_status)) => state.status = new_status,
+                    Ok(SupervisorEvent::Ready(info)) => state.port = Some(info.port),
+                    Ok(SupervisorEvent::Log(line)) => {
+                        state.log_lines.push(format!("[{}/{}] {}", line.stream, line.level, line.line));
+                        if state.log_lines.len() > LOG_HISTORY_LIMIT {
+                            state.log_lines.remove(0);
+                        }
+                    }
+                    Err(broadcast::error::RecvError::Lagged(_)) => {}
+                    Err(broadcast::error::RecvError::Closed) => return Ok(()),
+                }
+            }
+            _ = tick.tick() => {
+                state.status = status.load();
+                if handle_keypress(commands)? {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+/// Polls for a keypress without blocking; returns `Ok(true)` if the user asked to quit.
+fn handle_keypress(commands: &mpsc::UnboundedSender<SupervisorCommand>) -> std::io::Result<bool> {
+    if !event::poll(Duration::from_millis(0))? {
+        return Ok(false);
+    }
+
+    if let Event::Key(key) = event::read()? {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(true),
+            KeyCode::Char('r') => {
+                let _ = commands.send(SupervisorCommand::Restart);
+            }
+            _ => {}
+        }
+    }
+
+    Ok(false)
+}
+
+fn draw(frame: &mut Frame, state: &DashboardState) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(3), Constraint::Min(0)])
+        .split(frame.size());
+
+    let header = Paragraph::new(Line::from(vec![
+        Span::raw("Sentinel backend — status: "),
+        Span::styled(status_label(state.status), Style::default().fg(status_color(state.status))),
+        Span::raw(format!(
+            "  port: {}",
+            state.port.map(|p| p.to_string()).unwrap_or_else(|| "-".to_string())
+        )),
+    ]))
+    .block(Block::default().borders(Borders::ALL).title("Supervisor"));
+    frame.render_widget(header, chunks[0]);
+
+    let visible_rows = chunks[1].height.saturating_sub(2) as usize;
+    let log_items: Vec<ListItem> = state
+        .log_lines
+        .iter()
+        .rev()
+        .take(visible_rows)
+        .rev()
+        .map(|line| ListItem::new(line.as_str()))
+        .collect();
+    let logs = List::new(log_items).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Backend output (q: quit, r: restart)"),
+    );
+    frame.render_widget(logs, chunks[1]);
+}
@@ -3,27 +3,240 @@
     windows_subsystem = "windows"
 )]
 
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicU8, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Duration;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use serde::Serialize;
 use tauri::Manager;
 
+mod headless;
+
 /// Backend process state
 struct BackendProcess {
     child: Option<Child>,
+    port: Option<u16>,
 }
 
-/// Launch Python backend server
-fn launch_backend() -> Result<Child, String> {
-    println!("🚀 Launching FastAPI backend...");
-    
-    // Detect Python command (python3 on macOS/Linux, python on Windows)
-    let python_cmd = if cfg!(target_os = "windows") {
-        "python"
+/// The port the backend ended up bound to, emitted to the frontend once known.
+#[derive(Clone, Serialize)]
+struct BackendInfo {
+    port: u16,
+}
+
+/// Bind to an OS-assigned free port on localhost and return it, so the
+/// backend never collides with another process already holding `8000`.
+fn find_free_port() -> Result<u16, String> {
+    let listener = std::net::TcpListener::bind("127.0.0.1:0")
+        .map_err(|e| format!("Failed to bind a free port: {}", e))?;
+    let port = listener
+        .local_addr()
+        .map_err(|e| format!("Failed to read bound port: {}", e))?
+        .port();
+    // `listener` is dropped here, freeing the port for uvicorn to bind.
+    Ok(port)
+}
+
+/// A single line forwarded from the backend's stdout/stderr, emitted to the
+/// frontend as a `backend-log` event.
+#[derive(Clone, Serialize)]
+struct LogLine {
+    stream: &'static str,
+    level: &'static str,
+    line: String,
+    timestamp: u64,
+}
+
+/// Classify a uvicorn log line by its `LEVEL:` prefix (e.g. `INFO:`, `WARNING:`, `ERROR:`).
+fn classify_log_level(line: &str) -> &'static str {
+    let trimmed = line.trim_start();
+    if trimmed.starts_with("ERROR") {
+        "error"
+    } else if trimmed.starts_with("WARNING") {
+        "warning"
+    } else if trimmed.starts_with("INFO") {
+        "info"
+    } else {
+        "unknown"
+    }
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Events a running supervisor reports, independent of which frontend (the
+/// Tauri WebView or the headless TUI) is listening.
+#[derive(Clone)]
+enum SupervisorEvent {
+    Status(BackendStatus),
+    Log(LogLine),
+    Ready(BackendInfo),
+}
+
+/// Delivers supervisor events either to the Tauri WebView (as named events)
+/// or to a headless TUI (over a broadcast channel), so the supervisor logic
+/// itself doesn't need to know which frontend is driving it.
+#[derive(Clone)]
+enum Notifier {
+    Tauri(tauri::AppHandle),
+    Channel(tokio::sync::broadcast::Sender<SupervisorEvent>),
+}
+
+impl Notifier {
+    fn emit_status(&self, status: BackendStatus) {
+        match self {
+            Notifier::Tauri(app) => {
+                if let Err(e) = app.emit("backend-status", status) {
+                    eprintln!("❌ Failed to emit backend-status event: {}", e);
+                }
+            }
+            Notifier::Channel(tx) => {
+                let _ = tx.send(SupervisorEvent::Status(status));
+            }
+        }
+    }
+
+    fn emit_log(&self, log: LogLine) {
+        match self {
+            Notifier::Tauri(app) => {
+                if let Err(e) = app.emit("backend-log", log) {
+                    eprintln!("❌ Failed to emit backend-log event: {}", e);
+                }
+            }
+            Notifier::Channel(tx) => {
+                let _ = tx.send(SupervisorEvent::Log(log));
+            }
+        }
+    }
+
+    fn emit_ready(&self, info: BackendInfo) {
+        match self {
+            Notifier::Tauri(app) => {
+                if let Err(e) = app.emit("backend-ready", info) {
+                    eprintln!("❌ Failed to emit backend-ready event: {}", e);
+                }
+            }
+            Notifier::Channel(tx) => {
+                let _ = tx.send(SupervisorEvent::Ready(info));
+            }
+        }
+    }
+}
+
+/// Spawn a task that reads lines from a backend stdout/stderr pipe and
+/// forwards each one as a `backend-log` event.
+///
+/// The actual `BufReader::lines()` loop is blocking I/O, so it runs on a
+/// dedicated `spawn_blocking` thread rather than a tokio worker — otherwise
+/// it could starve the supervisor's polling/restart logic on small,
+/// few-core hosts (notably the headless/CI case).  Lines cross back into
+/// async-land over an unbounded channel, read by a small forwarding task
+/// that does the actual `notifier.emit_log`.
+fn spawn_log_forwarder<R>(notifier: Notifier, stream: &'static str, reader: R)
+where
+    R: std::io::Read + Send + 'static,
+{
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+
+    tokio::task::spawn_blocking(move || {
+        let buffered = BufReader::new(reader);
+        for line in buffered.lines() {
+            match line {
+                Ok(line) => {
+                    if tx.send(line).is_err() {
+                        break;
+                    }
+                }
+                Err(e) => {
+                    eprintln!("❌ Failed to read backend {} line: {}", stream, e);
+                    break;
+                }
+            }
+        }
+    });
+
+    tokio::spawn(async move {
+        while let Some(line) = rx.recv().await {
+            notifier.emit_log(LogLine {
+                stream,
+                level: classify_log_level(&line),
+                line,
+                timestamp: unix_timestamp(),
+            });
+        }
+    });
+}
+
+/// Check that `candidate` exists and can actually import the backend package,
+/// run from `backend_path` just like the real launch in `launch_backend` —
+/// some checkouts only resolve `sentinel_core` relative to that directory.
+fn validate_python_interpreter(candidate: &Path, backend_path: &Path) -> bool {
+    Command::new(candidate)
+        .args(&["-c", "import sentinel_core"])
+        .current_dir(backend_path)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map(|status| status.success())
+        .unwrap_or(false)
+}
+
+/// Resolve which Python interpreter to launch the backend with. Tried in order:
+/// a `SENTINEL_PYTHON` override, a bundled virtualenv next to `backend_path`,
+/// then `python3`/`python` on `PATH`. Each candidate is validated by actually
+/// importing `sentinel_core`, so a stale/wrong interpreter on PATH is skipped
+/// rather than failing later with a cryptic `ModuleNotFoundError`.
+fn resolve_python_interpreter(backend_path: &Path) -> Result<PathBuf, String> {
+    let mut candidates: Vec<PathBuf> = Vec::new();
+
+    if let Ok(override_path) = std::env::var("SENTINEL_PYTHON") {
+        candidates.push(PathBuf::from(override_path));
+    }
+
+    let venv_python = if cfg!(target_os = "windows") {
+        backend_path.join(".venv").join("Scripts").join("python.exe")
     } else {
-        "python3"
+        backend_path.join(".venv").join("bin").join("python")
     };
-    
+    candidates.push(venv_python);
+
+    if let Ok(path) = which::which("python3") {
+        candidates.push(path);
+    }
+    if let Ok(path) = which::which("python") {
+        candidates.push(path);
+    }
+
+    candidates
+        .iter()
+        .find(|candidate| validate_python_interpreter(candidate, backend_path))
+        .cloned()
+        .map(|candidate| {
+            println!("🐍 Using Python interpreter: {}", candidate.display());
+            candidate
+        })
+        .ok_or_else(|| {
+            format!(
+                "No working Python interpreter with `sentinel_core` importable was found. Tried: {}",
+                candidates
+                    .iter()
+                    .map(|p| p.display().to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        })
+}
+
+/// Launch Python backend server on the given port
+fn launch_backend(port: u16) -> Result<Child, String> {
+    println!("🚀 Launching FastAPI backend on port {}...", port);
+
     // Get backend path (relative to current directory)
     let backend_path = if cfg!(debug_assertions) {
         // Development: relative to workspace
@@ -32,10 +245,12 @@ fn launch_backend() -> Result<Child, String> {
         // Production: bundled with app
         "../sentinel-core"
     };
-    
+
     println!("📂 Backend path: {}", backend_path);
-    
-    let child = Command::new(python_cmd)
+
+    let python_cmd = resolve_python_interpreter(Path::new(backend_path))?;
+
+    let child = Command::new(&python_cmd)
         .args(&[
             "-m",
             "uvicorn",
@@ -43,7 +258,7 @@ fn launch_backend() -> Result<Child, String> {
             "--host",
             "127.0.0.1",
             "--port",
-            "8000",
+            &port.to_string(),
             "--log-level",
             "info",
         ])
@@ -58,14 +273,14 @@ fn launch_backend() -> Result<Child, String> {
 }
 
 /// Health check the backend server
-async fn wait_for_backend(max_attempts: u32) -> Result<(), String> {
+async fn wait_for_backend(max_attempts: u32, port: u16) -> Result<(), String> {
     let client = reqwest::Client::new();
-    let health_url = "http://localhost:8000/health";
-    
+    let health_url = format!("http://127.0.0.1:{}/health", port);
+
     for i in 0..max_attempts {
         println!("🏥 Health check attempt {}/{}", i + 1, max_attempts);
         
-        match client.get(health_url).send().await {
+        match client.get(&health_url).send().await {
             Ok(response) if response.status().is_success() => {
                 println!("✅ Backend is ready!");
                 return Ok(());
@@ -84,67 +299,465 @@ async fn wait_for_backend(max_attempts: u32) -> Result<(), String> {
     Err("Backend failed to start after maximum attempts".to_string())
 }
 
-/// Kill backend process
+/// How long to wait for the backend to exit gracefully before escalating to SIGKILL.
+const SHUTDOWN_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// Ask the backend process to shut down gracefully (SIGTERM on Unix, a close
+/// request via `taskkill` on Windows) rather than forcibly killing it.
+#[cfg(unix)]
+fn request_graceful_stop(child: &Child) -> std::io::Result<()> {
+    let ret = unsafe { libc::kill(child.id() as libc::pid_t, libc::SIGTERM) };
+    if ret == 0 {
+        Ok(())
+    } else {
+        Err(std::io::Error::last_os_error())
+    }
+}
+
+#[cfg(windows)]
+fn request_graceful_stop(child: &Child) -> std::io::Result<()> {
+    // GenerateConsoleCtrlEvent can only target a whole console process group,
+    // and uvicorn is not started in a group of its own (it shares ours), so
+    // signalling it that way would hit this process too; ask the OS to close it instead.
+    let status = Command::new("taskkill")
+        .args(&["/PID", &child.id().to_string()])
+        .status()?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(std::io::Error::new(std::io::ErrorKind::Other, "taskkill exited with failure"))
+    }
+}
+
+/// Wait for `child` to exit within `grace_period`, using the kernel's pidfd
+/// poll support on Linux to avoid a busy loop, with a polling fallback for
+/// other platforms or kernels too old to support pidfds.
+#[cfg(target_os = "linux")]
+fn wait_for_exit(child: &mut Child, grace_period: Duration) -> bool {
+    let pidfd = unsafe { libc::syscall(libc::SYS_pidfd_open, child.id() as libc::pid_t, 0) };
+    if pidfd >= 0 {
+        let pidfd = pidfd as i32;
+        let mut pfd = libc::pollfd {
+            fd: pidfd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let ret = unsafe { libc::poll(&mut pfd, 1, grace_period.as_millis() as i32) };
+        unsafe { libc::close(pidfd) };
+        if ret > 0 {
+            return true;
+        }
+        return matches!(child.try_wait(), Ok(Some(_)));
+    }
+
+    // pidfd_open unsupported (e.g. kernel < 5.3) — fall back to polling.
+    poll_try_wait(child, grace_period)
+}
+
+#[cfg(not(target_os = "linux"))]
+fn wait_for_exit(child: &mut Child, grace_period: Duration) -> bool {
+    poll_try_wait(child, grace_period)
+}
+
+fn poll_try_wait(child: &mut Child, grace_period: Duration) -> bool {
+    let poll_interval = Duration::from_millis(100);
+    let deadline = std::time::Instant::now() + grace_period;
+    while std::time::Instant::now() < deadline {
+        if matches!(child.try_wait(), Ok(Some(_))) {
+            return true;
+        }
+        std::thread::sleep(poll_interval);
+    }
+    matches!(child.try_wait(), Ok(Some(_)))
+}
+
+/// Kill backend process, giving it a chance to shut down gracefully first.
 fn kill_backend(backend: &mut BackendProcess) {
     if let Some(mut child) = backend.child.take() {
-        println!("🔪 Killing backend process (PID: {})...", child.id());
-        
-        match child.kill() {
-            Ok(_) => {
+        let pid = child.id();
+        println!("🛑 Requesting graceful shutdown of backend process (PID: {})...", pid);
+
+        let exited_gracefully = match request_graceful_stop(&child) {
+            Ok(()) => wait_for_exit(&mut child, SHUTDOWN_GRACE_PERIOD),
+            Err(e) => {
+                eprintln!("⚠️  Failed to request graceful shutdown: {}", e);
+                false
+            }
+        };
+
+        if !exited_gracefully {
+            println!("⏱️  Backend did not exit within grace period, escalating to SIGKILL...");
+            if let Err(e) = child.kill() {
+                eprintln!("❌ Failed to kill backend process: {}", e);
+            }
+        }
+
+        // Always reap the process so it doesn't linger as a zombie.
+        match child.wait() {
+            Ok(status) => println!("✅ Backend process terminated ({})", status),
+            Err(e) => eprintln!("❌ Failed to reap backend process: {}", e),
+        }
+    }
+}
+
+type SharedBackend = Arc<Mutex<BackendProcess>>;
+
+/// Lifecycle state of the backend process, read by the health monitor and by
+/// frontend-facing commands. Backed by an atomic so status reads never
+/// contend with the `SharedBackend` mutex guarding the child handle.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum BackendStatus {
+    Starting,
+    Ready,
+    Crashed,
+    Restarting,
+}
+
+impl BackendStatus {
+    fn from_u8(value: u8) -> Self {
+        match value {
+            0 => BackendStatus::Starting,
+            1 => BackendStatus::Ready,
+            2 => BackendStatus::Crashed,
+            _ => BackendStatus::Restarting,
+        }
+    }
+}
+
+struct AtomicBackendStatus(AtomicU8);
+
+impl AtomicBackendStatus {
+    fn new(status: BackendStatus) -> Self {
+        Self(AtomicU8::new(status as u8))
+    }
+
+    fn load(&self) -> BackendStatus {
+        BackendStatus::from_u8(self.0.load(Ordering::SeqCst))
+    }
+
+    fn store(&self, status: BackendStatus) {
+        self.0.store(status as u8, Ordering::SeqCst);
+    }
+}
+
+type SharedStatus = Arc<AtomicBackendStatus>;
+
+/// Maximum number of restart attempts before the supervisor gives up.
+const MAX_RESTART_ATTEMPTS: u32 = 5;
+/// Delay before the first restart attempt; doubles after each failed attempt.
+const INITIAL_RESTART_DELAY: Duration = Duration::from_secs(1);
+/// How often the monitor polls the backend's health once it's ready.
+const HEALTH_POLL_INTERVAL: Duration = Duration::from_secs(2);
+/// How long a single health check may take before it's considered a failure,
+/// so a backend that accepts connections but hangs doesn't block the
+/// supervisor loop forever.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(3);
+/// Consecutive failed health checks required before declaring the backend
+/// crashed, so a single transient blip doesn't trigger an unnecessary restart.
+const CONSECUTIVE_FAILURES_BEFORE_CRASH: u32 = 2;
+
+/// Update the backend status and notify whichever frontend is listening.
+fn set_status(notifier: &Notifier, status: &SharedStatus, new_status: BackendStatus) {
+    status.store(new_status);
+    notifier.emit_status(new_status);
+}
+
+/// Pick a free port, launch the backend on it, wire up log forwarding, and
+/// wait for it to report healthy. Used both for the initial launch and for
+/// supervisor-driven restarts.
+async fn start_backend_process(notifier: Notifier, backend: &SharedBackend) -> Result<(), String> {
+    let port = find_free_port()?;
+    let mut child = launch_backend(port)?;
+
+    if let Some(stdout) = child.stdout.take() {
+        spawn_log_forwarder(notifier.clone(), "stdout", stdout);
+    }
+    if let Some(stderr) = child.stderr.take() {
+        spawn_log_forwarder(notifier.clone(), "stderr", stderr);
+    }
+
+    {
+        let mut guard = backend.lock().unwrap();
+        guard.child = Some(child);
+        guard.port = Some(port);
+    }
+
+    wait_for_backend(30, port).await?;
+
+    notifier.emit_ready(BackendInfo { port });
+    Ok(())
+}
+
+/// One-shot health check used by the supervisor loop, distinct from the
+/// retrying `wait_for_backend` used during startup. Bounded by
+/// `HEALTH_CHECK_TIMEOUT` so a backend that accepts the connection but never
+/// responds can't stall the supervisor's select loop indefinitely.
+async fn check_health_once(client: &reqwest::Client, port: u16) -> bool {
+    let health_url = format!("http://127.0.0.1:{}/health", port);
+    client
+        .get(&health_url)
+        .timeout(HEALTH_CHECK_TIMEOUT)
+        .send()
+        .await
+        .map(|response| response.status().is_success())
+        .unwrap_or(false)
+}
+
+/// How a crash-restart backoff sequence ended.
+enum RestartOutcome {
+    Recovered,
+    GaveUp,
+    ShutdownRequested,
+}
+
+/// Kill whatever's left of the current child (if any) and attempt restarts
+/// with doubling backoff, up to `MAX_RESTART_ATTEMPTS`. Each wait races
+/// against `commands` so a `Shutdown` (or another `Restart`) isn't stuck
+/// behind up to ~31s of accumulated backoff delay.
+async fn attempt_restart_with_backoff(
+    notifier: &Notifier,
+    backend: &SharedBackend,
+    status: &SharedStatus,
+    commands: &mut tokio::sync::mpsc::UnboundedReceiver<SupervisorCommand>,
+) -> RestartOutcome {
+    let mut delay = INITIAL_RESTART_DELAY;
+
+    for attempt in 1..=MAX_RESTART_ATTEMPTS {
+        set_status(notifier, status, BackendStatus::Restarting);
+        println!("🔁 Restart attempt {}/{} (waiting {:?})...", attempt, MAX_RESTART_ATTEMPTS, delay);
+
+        tokio::select! {
+            _ = tokio::time::sleep(delay) => {}
+            command = commands.recv() => {
+                match command {
+                    Some(SupervisorCommand::Shutdown) | None => {
+                        println!("🛑 Shutdown requested during restart backoff");
+                        kill_backend(&mut backend.lock().unwrap());
+                        return RestartOutcome::ShutdownRequested;
+                    }
+                    Some(SupervisorCommand::Restart) => {
+                        println!("🔁 Restart re-requested, skipping remaining backoff");
+                    }
+                }
+            }
+        }
+
+        {
+            let mut guard = backend.lock().unwrap();
+            if let Some(mut child) = guard.child.take() {
+                let _ = child.kill();
                 let _ = child.wait();
-                println!("✅ Backend process terminated");
+            }
+            guard.port = None;
+        }
+
+        match start_backend_process(notifier.clone(), backend).await {
+            Ok(()) => {
+                println!("✅ Backend restarted successfully");
+                set_status(notifier, status, BackendStatus::Ready);
+                return RestartOutcome::Recovered;
             }
             Err(e) => {
-                eprintln!("❌ Failed to kill backend process: {}", e);
+                eprintln!("❌ Restart attempt {} failed: {}", attempt, e);
+                delay *= 2;
+            }
+        }
+    }
+
+    eprintln!("🛑 Backend failed to restart after {} attempts, giving up", MAX_RESTART_ATTEMPTS);
+    set_status(notifier, status, BackendStatus::Crashed);
+    RestartOutcome::GaveUp
+}
+
+/// Commands accepted by the supervisor actor. Both the Tauri `restart_backend`
+/// command and the headless TUI's keybinds send these over the same channel,
+/// so the actor is the single place that owns the child handle and drives state.
+enum SupervisorCommand {
+    Restart,
+    Shutdown,
+}
+
+/// The supervisor actor: launches the backend, owns its child handle for the
+/// rest of its lifetime, periodically health-checks it, and reacts to
+/// `SupervisorCommand`s sent by whichever frontend is attached.
+async fn run_supervisor(
+    notifier: Notifier,
+    backend: SharedBackend,
+    status: SharedStatus,
+    mut commands: tokio::sync::mpsc::UnboundedReceiver<SupervisorCommand>,
+) {
+    match start_backend_process(notifier.clone(), &backend).await {
+        Ok(()) => {
+            println!("✅ Backend ready");
+            set_status(&notifier, &status, BackendStatus::Ready);
+        }
+        Err(e) => {
+            eprintln!("❌ Failed to launch backend: {}", e);
+            set_status(&notifier, &status, BackendStatus::Crashed);
+        }
+    }
+
+    /// What the select below observed, decoupled from `commands` so it isn't
+    /// borrowed by two branches of the same `select!` at once.
+    enum Woken {
+        HealthTick,
+        Command(Option<SupervisorCommand>),
+    }
+
+    let health_client = reqwest::Client::new();
+    let mut consecutive_health_failures: u32 = 0;
+
+    loop {
+        let woken = tokio::select! {
+            _ = tokio::time::sleep(HEALTH_POLL_INTERVAL) => Woken::HealthTick,
+            command = commands.recv() => Woken::Command(command),
+        };
+
+        match woken {
+            Woken::HealthTick => {
+                if status.load() != BackendStatus::Ready {
+                    continue;
+                }
+
+                let (exited, port) = {
+                    let mut guard = backend.lock().unwrap();
+                    let exited = match guard.child.as_mut() {
+                        Some(child) => matches!(child.try_wait(), Ok(Some(_))),
+                        None => true,
+                    };
+                    (exited, guard.port)
+                };
+
+                let healthy = match (exited, port) {
+                    (true, _) => false,
+                    (false, Some(port)) => check_health_once(&health_client, port).await,
+                    (false, None) => false,
+                };
+
+                if healthy {
+                    consecutive_health_failures = 0;
+                    continue;
+                }
+
+                // A process exit is unambiguous; a failed/slow health check on a
+                // still-running process might just be a transient blip, so only
+                // declare it crashed after a few in a row.
+                if !exited {
+                    consecutive_health_failures += 1;
+                    eprintln!(
+                        "⚠️  Health check failed ({}/{} consecutive)",
+                        consecutive_health_failures, CONSECUTIVE_FAILURES_BEFORE_CRASH
+                    );
+                    if consecutive_health_failures < CONSECUTIVE_FAILURES_BEFORE_CRASH {
+                        continue;
+                    }
+                }
+
+                consecutive_health_failures = 0;
+                eprintln!("💥 Backend appears to have crashed (process exited: {}, healthy: {})", exited, healthy);
+                set_status(&notifier, &status, BackendStatus::Crashed);
+                if let RestartOutcome::ShutdownRequested =
+                    attempt_restart_with_backoff(&notifier, &backend, &status, &mut commands).await
+                {
+                    return;
+                }
+            }
+            Woken::Command(Some(SupervisorCommand::Restart)) => {
+                println!("🔁 Restart requested");
+                set_status(&notifier, &status, BackendStatus::Restarting);
+                {
+                    let mut guard = backend.lock().unwrap();
+                    // The child is typically still healthy here (unlike the
+                    // crash-driven backoff path), so give it the same chance
+                    // to shut down gracefully as the app-exit path does.
+                    kill_backend(&mut guard);
+                    guard.port = None;
+                }
+                match start_backend_process(notifier.clone(), &backend).await {
+                    Ok(()) => set_status(&notifier, &status, BackendStatus::Ready),
+                    Err(e) => {
+                        eprintln!("❌ Restart failed: {}", e);
+                        set_status(&notifier, &status, BackendStatus::Crashed);
+                    }
+                }
+            }
+            Woken::Command(Some(SupervisorCommand::Shutdown)) | Woken::Command(None) => {
+                println!("🛑 Supervisor shutting down...");
+                kill_backend(&mut backend.lock().unwrap());
+                return;
             }
         }
     }
 }
 
+/// Returns the port the backend is currently listening on, if it has started.
+#[tauri::command]
+fn backend_port(backend: tauri::State<SharedBackend>) -> Option<u16> {
+    backend.lock().unwrap().port
+}
+
+/// Returns the backend's current supervisor status.
+#[tauri::command]
+fn backend_status(status: tauri::State<SharedStatus>) -> BackendStatus {
+    status.load()
+}
+
+/// Manually triggers a backend restart, bypassing the monitor's backoff schedule.
+#[tauri::command]
+fn restart_backend(commands: tauri::State<tokio::sync::mpsc::UnboundedSender<SupervisorCommand>>) -> Result<(), String> {
+    commands
+        .send(SupervisorCommand::Restart)
+        .map_err(|_| "Supervisor is not running".to_string())
+}
+
 fn main() {
-    let backend = Arc::new(Mutex::new(BackendProcess { child: None }));
-    
+    if headless::is_requested() {
+        headless::run();
+        return;
+    }
+
+    let backend: SharedBackend = Arc::new(Mutex::new(BackendProcess { child: None, port: None }));
+    let status: SharedStatus = Arc::new(AtomicBackendStatus::new(BackendStatus::Starting));
+    let (commands_tx, commands_rx) = tokio::sync::mpsc::unbounded_channel();
+    let commands_tx_for_exit = commands_tx.clone();
+    // The supervisor actor is the single place that owns the child handle, so
+    // on exit we hand it a `Shutdown` command and wait for it to finish
+    // instead of reaching into `backend` directly from the exit handler.
+    let supervisor_handle: Arc<Mutex<Option<tauri::async_runtime::JoinHandle<()>>>> = Arc::new(Mutex::new(None));
+    let supervisor_handle_for_exit = supervisor_handle.clone();
+
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_fs::init())
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_process::init())
+        .manage(backend.clone())
+        .manage(status.clone())
+        .manage(commands_tx)
+        .invoke_handler(tauri::generate_handler![backend_port, backend_status, restart_backend])
         .setup(move |app| {
+            let notifier = Notifier::Tauri(app.handle().clone());
             let backend_clone = backend.clone();
-            let _handle = app.handle().clone(); // Handle for future use if needed
-            
-            // Launch backend on startup
-            tauri::async_runtime::spawn(async move {
-                // Start backend
-                match launch_backend() {
-                    Ok(child) => {
-                        backend_clone.lock().unwrap().child = Some(child);
-                        
-                        // Wait for backend to be ready
-                        match wait_for_backend(30).await {
-                            Ok(_) => {
-                                println!("✅ Backend ready, UI should load now");
-                            }
-                            Err(e) => {
-                                eprintln!("❌ Backend health check failed: {}", e);
-                            }
-                        }
-                    }
-                    Err(e) => {
-                        eprintln!("❌ Failed to launch backend: {}", e);
-                    }
-                }
-            });
-            
+            let status_clone = status.clone();
+
+            // Launch the backend and hand off to the supervisor actor.
+            let handle = tauri::async_runtime::spawn(run_supervisor(notifier, backend_clone, status_clone, commands_rx));
+            *supervisor_handle.lock().unwrap() = Some(handle);
+
             Ok(())
         })
         .build(tauri::generate_context!())
         .expect("error while building tauri application")
         .run(move |_app_handle, event| {
             if let tauri::RunEvent::ExitRequested { .. } = event {
-                // Kill backend on exit
                 println!("🛑 Application exiting, cleaning up...");
-                kill_backend(&mut backend.lock().unwrap());
+                // Ask the actor to shut down gracefully and wait for it to reap
+                // the child, rather than killing it out from under the actor.
+                let _ = commands_tx_for_exit.send(SupervisorCommand::Shutdown);
+                if let Some(handle) = supervisor_handle_for_exit.lock().unwrap().take() {
+                    let _ = tauri::async_runtime::block_on(handle);
+                }
             }
         });
 }